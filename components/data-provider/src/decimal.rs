@@ -1,13 +1,73 @@
 // Decimal types
 
+use std::convert::TryFrom;
 use std::prelude::v1::*;
 
 use serde::{Deserialize, Serialize};
 use smallstr::SmallString;
 
-#[derive(PartialEq, Copy, Clone, Debug)]
+use crate::{TinyStr8, TinyStrError};
+
+/// A BCP-47 numbering system identifier, e.g. `"latn"` or `"arab"`. Stored
+/// inline like the `SymbolsV1` separators below since these are always
+/// short ASCII subtags.
+pub type NumberingSystem = SmallString<[u8; 8]>;
+
+/// The default numbering system for regions that don't use `latn`. Not
+/// exhaustive: covers the handful of regions most likely to be exercised
+/// while CLDR's full `numberingSystems.json` data isn't wired up yet.
+fn default_numbering_system_for_region(region: &str) -> &'static str {
+    match region {
+        "EG" | "SD" => "arab",
+        "IR" => "arabext",
+        "BD" => "beng",
+        "MM" => "mymr",
+        _ => "latn",
+    }
+}
+
+/// Reads the `nu` (numbering system) keyword out of a locale's `-u-`
+/// (Unicode) extension, e.g. `"ar-EG-u-nu-arab"` -> `Some("arab")`.
+///
+/// A BCP-47 extension runs until the next singleton (single-character)
+/// subtag, which starts a different extension (e.g. `-t-` transform,
+/// `-x-` private use), so the scan stops there instead of reading to the
+/// end of the locale string — otherwise a locale like
+/// `"en-u-ca-buddhist-t-und-nu-arab"` would misread the `t` extension's
+/// `nu-arab` as the `u` extension's `nu` keyword.
+fn numbering_system_keyword(locale: &str) -> Option<&str> {
+    let extension_start = locale.find("-u-")? + "-u-".len();
+    let mut subtags = locale[extension_start..]
+        .split('-')
+        .take_while(|subtag| subtag.len() != 1);
+    while let Some(subtag) = subtags.next() {
+        if subtag == "nu" {
+            return subtags.next();
+        }
+    }
+    None
+}
+
+/// Resolves the numbering system a locale requests: the `-u-nu-` keyword if
+/// present, otherwise `region`'s default.
+pub fn resolve_numbering_system(locale: &str, region: &str) -> NumberingSystem {
+    numbering_system_keyword(locale)
+        .unwrap_or_else(|| default_numbering_system_for_region(region))
+        .into()
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
 pub enum Key {
-    SymbolsV1 = 1,
+    /// Decimal symbols for a given numbering system, e.g. `nu=arab`.
+    SymbolsV1(NumberingSystem),
+}
+
+impl Key {
+    /// Builds the `SymbolsV1` key for `locale`, resolving its `-u-nu-`
+    /// keyword (or `region`'s default numbering system when absent).
+    pub fn symbols_v1_for_locale(locale: &str, region: &str) -> Self {
+        Self::SymbolsV1(resolve_numbering_system(locale, region))
+    }
 }
 
 impl From<Key> for crate::Key {
@@ -17,9 +77,59 @@ impl From<Key> for crate::Key {
 }
 
 // TODO: de-duplicate the name "SymbolsV1" between Key and the struct
-#[derive(Debug, PartialEq, Clone, Deserialize, Serialize)]
+#[derive(Debug, PartialEq, Clone, Copy, Deserialize, Serialize)]
 pub struct SymbolsV1 {
     pub zero_digit: char,
-    pub decimal_separator: SmallString<[u8; 8]>,
-    pub grouping_separator: SmallString<[u8; 8]>,
+    pub decimal_separator: TinyStr8,
+    pub grouping_separator: TinyStr8,
+}
+
+impl SymbolsV1 {
+    /// Builds a `SymbolsV1`, erroring instead of truncating if either
+    /// separator is too long to store inline. This is the check that
+    /// should run at data-build time, before a separator that's too long
+    /// for `TinyStr8` ever reaches the bundled data table.
+    pub fn try_new(
+        zero_digit: char,
+        decimal_separator: &str,
+        grouping_separator: &str,
+    ) -> Result<Self, TinyStrError> {
+        Ok(Self {
+            zero_digit,
+            decimal_separator: TinyStr8::try_from(decimal_separator)?,
+            grouping_separator: TinyStr8::try_from(grouping_separator)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reads_nu_keyword() {
+        assert_eq!(numbering_system_keyword("ar-EG-u-nu-arab"), Some("arab"));
+        assert_eq!(numbering_system_keyword("en-US"), None);
+    }
+
+    #[test]
+    fn test_nu_keyword_stops_at_next_singleton_extension() {
+        // The `t` (transform) extension's own `nu-arab` subtags must not be
+        // misread as the `u` extension's `nu` keyword.
+        assert_eq!(
+            numbering_system_keyword("en-u-ca-buddhist-t-und-nu-arab"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_falls_back_to_region_default() {
+        assert_eq!(resolve_numbering_system("ar-EG", "EG"), "arab");
+        assert_eq!(resolve_numbering_system("en-US", "US"), "latn");
+    }
+
+    #[test]
+    fn test_explicit_keyword_wins_over_region_default() {
+        assert_eq!(resolve_numbering_system("ar-EG-u-nu-latn", "EG"), "latn");
+    }
 }
@@ -0,0 +1,15 @@
+//! Data keys and the serializable structs they resolve to. A `Key`
+//! identifies a particular shape of locale data (e.g. decimal symbols); the
+//! matching struct (e.g. `decimal::SymbolsV1`) is what a `DataProvider`
+//! hands back once it has resolved a requested locale to actual data.
+
+pub mod decimal;
+pub mod tiny_str;
+
+pub use tiny_str::{TinyStr8, TinyStrError};
+
+/// Identifies a category of locale data a `DataProvider` can resolve.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Key {
+    Decimal(decimal::Key),
+}
@@ -0,0 +1,150 @@
+// A fixed-capacity, heap-free string for the short ASCII-range separators
+// (and similar short tokens) that show up throughout locale data tables.
+
+use std::convert::TryFrom;
+use std::fmt;
+use std::ops::Deref;
+use std::str;
+
+use serde::de::{self, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// The inline capacity of a [`TinyStr8`], in UTF-8 bytes.
+pub const TINY_STR8_CAPACITY: usize = 8;
+
+/// A `Copy`, heap-free string of at most 8 UTF-8 bytes.
+///
+/// Unlike `SmallString`, which falls back to a heap allocation once its
+/// inline capacity is exceeded, `TinyStr8` rejects longer input at
+/// construction time instead. That keeps every instance — and therefore any
+/// struct built only from these, like `SymbolsV1` — `Copy` and
+/// allocation-free, which is what lets bundled data tables be memory-mapped
+/// or embedded without a per-field allocation. Despite the name, the
+/// capacity is byte-based, not ASCII-only: a short non-ASCII separator such
+/// as U+00A0 NO-BREAK SPACE (2 UTF-8 bytes) fits just as well as the
+/// (more common) single-byte ASCII case the name optimizes for.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TinyStr8 {
+    bytes: [u8; TINY_STR8_CAPACITY],
+    len: u8,
+}
+
+/// An error produced when a string does not fit in a [`TinyStr8`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum TinyStrError {
+    /// The input was longer than the inline capacity.
+    TooLong { capacity: usize, len: usize },
+}
+
+impl fmt::Display for TinyStrError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TooLong { capacity, len } => write!(
+                f,
+                "string of {} bytes exceeds the {}-byte inline capacity",
+                len, capacity
+            ),
+        }
+    }
+}
+
+impl std::error::Error for TinyStrError {}
+
+impl TinyStr8 {
+    /// Borrows the string as a `&str`.
+    pub fn as_str(&self) -> &str {
+        // Invariant: `bytes[..len]` is always valid UTF-8, enforced by the
+        // only two ways to build a `TinyStr8`: `TryFrom<&str>` and
+        // `Deserialize`, both of which copy from an existing `&str`.
+        unsafe { str::from_utf8_unchecked(&self.bytes[..self.len as usize]) }
+    }
+}
+
+impl TryFrom<&str> for TinyStr8 {
+    type Error = TinyStrError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        if input.len() > TINY_STR8_CAPACITY {
+            return Err(TinyStrError::TooLong {
+                capacity: TINY_STR8_CAPACITY,
+                len: input.len(),
+            });
+        }
+        let mut bytes = [0u8; TINY_STR8_CAPACITY];
+        bytes[..input.len()].copy_from_slice(input.as_bytes());
+        Ok(Self { bytes, len: input.len() as u8 })
+    }
+}
+
+impl Deref for TinyStr8 {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Debug for TinyStr8 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl fmt::Display for TinyStr8 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), f)
+    }
+}
+
+impl Serialize for TinyStr8 {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+struct TinyStr8Visitor;
+
+impl<'de> Visitor<'de> for TinyStr8Visitor {
+    type Value = TinyStr8;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a string of at most {} bytes", TINY_STR8_CAPACITY)
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        TinyStr8::try_from(v).map_err(de::Error::custom)
+    }
+}
+
+impl<'de> Deserialize<'de> for TinyStr8 {
+    /// Deserializes directly into the inline byte array, with no
+    /// intermediate `String` allocation.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(TinyStr8Visitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trips_short_string() {
+        let s = TinyStr8::try_from(",").unwrap();
+        assert_eq!(s.as_str(), ",");
+    }
+
+    #[test]
+    fn test_accepts_short_non_ascii_string() {
+        let s = TinyStr8::try_from("\u{00A0}").unwrap();
+        assert_eq!(s.as_str(), "\u{00A0}");
+    }
+
+    #[test]
+    fn test_rejects_over_capacity_string() {
+        assert_eq!(
+            TinyStr8::try_from("123456789"),
+            Err(TinyStrError::TooLong { capacity: 8, len: 9 })
+        );
+    }
+}
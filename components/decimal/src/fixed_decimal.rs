@@ -0,0 +1,248 @@
+// An arbitrary-magnitude decimal number with explicit significant/visible
+// digit positions, e.g. "1.50" is distinct from "1.5": both equal 1.5, but
+// the former has a visible trailing zero that formatting should preserve.
+
+use std::str::FromStr;
+
+/// An error originating from parsing a string into a [`FixedDecimal`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum FixedDecimalError {
+    /// The input string was not a valid decimal number.
+    Invalid,
+}
+
+impl std::fmt::Display for FixedDecimalError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Invalid => write!(f, "invalid decimal number"),
+        }
+    }
+}
+
+impl std::error::Error for FixedDecimalError {}
+
+/// An arbitrary-magnitude decimal number, stored as a sequence of digits
+/// plus the power of ten of the first (most significant) digit.
+///
+/// `digits[0]` is worth `10^magnitude`; each subsequent digit is worth one
+/// power of ten less, down to `10^(magnitude - digits.len() + 1)`, which may
+/// be negative (a fraction digit). This keeps visible digit positions —
+/// including insignificant leading/trailing zeros — distinguishable from
+/// the number's mathematical value, which is what lets
+/// [`Self::pad_start`]/[`Self::pad_end`]/[`Self::round_fraction`] control
+/// exactly what a formatter renders.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FixedDecimal {
+    digits: Vec<u8>,
+    magnitude: i16,
+    is_negative: bool,
+}
+
+impl FixedDecimal {
+    /// The power of ten of the most significant visible digit.
+    pub fn magnitude(&self) -> i16 {
+        self.magnitude
+    }
+
+    /// The power of ten of the least significant visible digit; negative
+    /// when the number has visible fraction digits.
+    pub fn lower_magnitude(&self) -> i16 {
+        self.magnitude - self.digits.len() as i16 + 1
+    }
+
+    /// Whether the number is negative. Zero is never negative.
+    pub fn is_negative(&self) -> bool {
+        self.is_negative
+    }
+
+    /// The digit at the given power of ten, or `0` if `magnitude` falls
+    /// outside the visible digit range.
+    pub fn digit_at(&self, magnitude: i16) -> u8 {
+        if magnitude > self.magnitude || magnitude < self.lower_magnitude() {
+            0
+        } else {
+            self.digits[(self.magnitude - magnitude) as usize]
+        }
+    }
+
+    fn ensure_nonempty(&mut self) {
+        if self.digits.is_empty() {
+            self.digits.push(0);
+            self.magnitude = 0;
+        }
+    }
+
+    /// Pads with leading zeros so at least `min_integer_digits` integer
+    /// digits are visible, e.g. `5` with `min_integer_digits(3)` becomes
+    /// `"005"`.
+    pub fn pad_start(&mut self, min_integer_digits: u16) {
+        self.ensure_nonempty();
+        let target_magnitude = min_integer_digits as i16 - 1;
+        while self.magnitude < target_magnitude {
+            self.digits.insert(0, 0);
+            self.magnitude += 1;
+        }
+    }
+
+    /// Pads with trailing zeros so at least `min_fraction_digits` fraction
+    /// digits are visible, e.g. `1.5` with `min_fraction_digits(3)` becomes
+    /// `"1.500"`.
+    pub fn pad_end(&mut self, min_fraction_digits: u16) {
+        self.ensure_nonempty();
+        let target_lower_magnitude = -(min_fraction_digits as i16);
+        while self.lower_magnitude() > target_lower_magnitude {
+            self.digits.push(0);
+        }
+    }
+
+    /// Rounds (half-up) so at most `max_fraction_digits` fraction digits
+    /// remain visible, e.g. `1.567` with `max_fraction_digits(2)` becomes
+    /// `"1.57"`.
+    pub fn round_fraction(&mut self, max_fraction_digits: u16) {
+        let target_lower_magnitude = -(max_fraction_digits as i16);
+        if self.digits.is_empty() || self.lower_magnitude() >= target_lower_magnitude {
+            return;
+        }
+        let keep = (self.magnitude - target_lower_magnitude + 1).max(0) as usize;
+        let round_up = self.digits.get(keep).map_or(false, |&d| d >= 5);
+        self.digits.truncate(keep);
+        if round_up {
+            self.increment();
+        }
+        if self.digits.iter().all(|&d| d == 0) {
+            self.is_negative = false;
+        }
+    }
+
+    /// Adds one to the visible digits, carrying as needed (`"99"` ->
+    /// `"100"`).
+    fn increment(&mut self) {
+        let mut i = self.digits.len();
+        loop {
+            if i == 0 {
+                self.digits.insert(0, 1);
+                self.magnitude += 1;
+                return;
+            }
+            i -= 1;
+            if self.digits[i] == 9 {
+                self.digits[i] = 0;
+            } else {
+                self.digits[i] += 1;
+                return;
+            }
+        }
+    }
+}
+
+macro_rules! impl_unsigned_integer_type {
+    ($ty:ty) => {
+        impl From<$ty> for FixedDecimal {
+            fn from(input: $ty) -> Self {
+                let digits: Vec<u8> = input
+                    .to_string()
+                    .bytes()
+                    .map(|b| b - b'0')
+                    .collect();
+                let magnitude = digits.len() as i16 - 1;
+                Self { digits, magnitude, is_negative: false }
+            }
+        }
+    };
+}
+
+impl_unsigned_integer_type!(u8);
+impl_unsigned_integer_type!(u16);
+impl_unsigned_integer_type!(u32);
+impl_unsigned_integer_type!(u64);
+impl_unsigned_integer_type!(usize);
+
+impl FromStr for FixedDecimal {
+    type Err = FixedDecimalError;
+
+    /// Parses a decimal number, preserving its visible digit positions,
+    /// e.g. `"1.50"` keeps the trailing zero as a visible fraction digit.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let (is_negative, unsigned) = match input.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, input),
+        };
+
+        let mut parts = unsigned.splitn(2, '.');
+        let integer_part = parts.next().ok_or(FixedDecimalError::Invalid)?;
+        let fraction_part = parts.next().unwrap_or("");
+
+        if integer_part.is_empty() || !integer_part.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(FixedDecimalError::Invalid);
+        }
+        if !fraction_part.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(FixedDecimalError::Invalid);
+        }
+
+        let mut digits: Vec<u8> = Vec::with_capacity(integer_part.len() + fraction_part.len());
+        digits.extend(integer_part.bytes().map(|b| b - b'0'));
+        digits.extend(fraction_part.bytes().map(|b| b - b'0'));
+        let magnitude = integer_part.len() as i16 - 1;
+
+        let is_negative = is_negative && digits.iter().any(|&d| d != 0);
+        Ok(Self { digits, magnitude, is_negative })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parses_integer() {
+        let decimal: FixedDecimal = "1234".parse().unwrap();
+        assert_eq!(decimal.magnitude(), 3);
+        assert_eq!(decimal.lower_magnitude(), 0);
+        assert_eq!(decimal.digit_at(3), 1);
+        assert_eq!(decimal.digit_at(0), 4);
+    }
+
+    #[test]
+    fn test_preserves_trailing_zero() {
+        let decimal: FixedDecimal = "1.50".parse().unwrap();
+        assert_eq!(decimal.lower_magnitude(), -2);
+        assert_eq!(decimal.digit_at(-1), 5);
+        assert_eq!(decimal.digit_at(-2), 0);
+    }
+
+    #[test]
+    fn test_pad_start() {
+        let mut decimal: FixedDecimal = "5".parse().unwrap();
+        decimal.pad_start(3);
+        assert_eq!(decimal.magnitude(), 2);
+        assert_eq!(decimal.digit_at(2), 0);
+        assert_eq!(decimal.digit_at(0), 5);
+    }
+
+    #[test]
+    fn test_pad_end() {
+        let mut decimal: FixedDecimal = "1.5".parse().unwrap();
+        decimal.pad_end(3);
+        assert_eq!(decimal.lower_magnitude(), -3);
+        assert_eq!(decimal.digit_at(-3), 0);
+    }
+
+    #[test]
+    fn test_round_fraction_rounds_up_with_carry() {
+        let mut decimal: FixedDecimal = "1.99".parse().unwrap();
+        decimal.round_fraction(1);
+        // 1.99 rounds to 2.0: the carry lands in the existing leading
+        // digit, it doesn't grow a new one.
+        assert_eq!(decimal.digit_at(1), 0);
+        assert_eq!(decimal.digit_at(0), 2);
+        assert_eq!(decimal.digit_at(-1), 0);
+    }
+
+    #[test]
+    fn test_negative() {
+        let decimal: FixedDecimal = "-3.5".parse().unwrap();
+        assert!(decimal.is_negative());
+        let zero: FixedDecimal = "-0".parse().unwrap();
+        assert!(!zero.is_negative());
+    }
+}
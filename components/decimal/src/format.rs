@@ -0,0 +1,122 @@
+// Renders a `FixedDecimal` to a string using a locale's `SymbolsV1`.
+
+use icu_data_provider::decimal::SymbolsV1;
+
+use crate::FixedDecimal;
+
+/// Controls where grouping separators (e.g. the `,` in `1,234,567`) are
+/// inserted into the integer part of a formatted number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GroupingStrategy {
+    /// The number of integer digits between grouping separators, e.g. `3`
+    /// for `1,234,567`. `0` disables grouping entirely.
+    pub group_size: u8,
+    /// The minimum number of integer digits a number must have before any
+    /// grouping separator is inserted, e.g. with a threshold of `4`, `123`
+    /// is left ungrouped but `1234` becomes `1,234`.
+    pub min_grouping_digits: u8,
+}
+
+impl Default for GroupingStrategy {
+    /// Groups every 3 integer digits, with no minimum threshold.
+    fn default() -> Self {
+        Self { group_size: 3, min_grouping_digits: 1 }
+    }
+}
+
+impl GroupingStrategy {
+    /// A strategy that never inserts grouping separators.
+    pub fn never() -> Self {
+        Self { group_size: 0, min_grouping_digits: u8::MAX }
+    }
+}
+
+/// Formats [`FixedDecimal`] values into locale-appropriate strings using a
+/// [`SymbolsV1`], e.g. rendering `1234.5` as `"1,234.5"` for `en` or
+/// `"١٬٢٣٤٫٥"` for `ar-u-nu-arab`.
+#[derive(Debug, Clone, Copy)]
+pub struct FixedDecimalFormat<'a> {
+    symbols: &'a SymbolsV1,
+    grouping: GroupingStrategy,
+}
+
+impl<'a> FixedDecimalFormat<'a> {
+    /// Creates a formatter using the default grouping strategy (every 3
+    /// integer digits).
+    pub fn new(symbols: &'a SymbolsV1) -> Self {
+        Self { symbols, grouping: GroupingStrategy::default() }
+    }
+
+    /// Creates a formatter with an explicit grouping strategy.
+    pub fn with_grouping(symbols: &'a SymbolsV1, grouping: GroupingStrategy) -> Self {
+        Self { symbols, grouping }
+    }
+
+    fn locale_digit(&self, digit: u8) -> char {
+        char::from_u32(self.symbols.zero_digit as u32 + digit as u32).unwrap_or('?')
+    }
+
+    /// Renders `value` to a string, e.g. `"1,234.50"`.
+    pub fn format(&self, value: &FixedDecimal) -> String {
+        let mut result = String::new();
+        if value.is_negative() {
+            result.push('-');
+        }
+
+        let integer_digit_count = (value.magnitude().max(0) + 1) as u32;
+        for position in (0..integer_digit_count).rev() {
+            let magnitude = position as i16;
+            result.push(self.locale_digit(value.digit_at(magnitude)));
+
+            let should_group = self.grouping.group_size > 0
+                && magnitude > 0
+                && magnitude % self.grouping.group_size as i16 == 0
+                && integer_digit_count >= self.grouping.min_grouping_digits as u32;
+            if should_group {
+                result.push_str(&self.symbols.grouping_separator);
+            }
+        }
+
+        if value.lower_magnitude() < 0 {
+            result.push_str(&self.symbols.decimal_separator);
+            for magnitude in (value.lower_magnitude()..0).rev() {
+                result.push(self.locale_digit(value.digit_at(magnitude)));
+            }
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn en_symbols() -> SymbolsV1 {
+        SymbolsV1::try_new('0', ".", ",").unwrap()
+    }
+
+    #[test]
+    fn test_formats_with_grouping_and_fraction() {
+        let symbols = en_symbols();
+        let formatter = FixedDecimalFormat::new(&symbols);
+        let value: FixedDecimal = "1234567.5".parse().unwrap();
+        assert_eq!(formatter.format(&value), "1,234,567.5");
+    }
+
+    #[test]
+    fn test_formats_without_grouping() {
+        let symbols = en_symbols();
+        let formatter = FixedDecimalFormat::with_grouping(&symbols, GroupingStrategy::never());
+        let value: FixedDecimal = "1234".parse().unwrap();
+        assert_eq!(formatter.format(&value), "1234");
+    }
+
+    #[test]
+    fn test_formats_negative() {
+        let symbols = en_symbols();
+        let formatter = FixedDecimalFormat::new(&symbols);
+        let value: FixedDecimal = "-42".parse().unwrap();
+        assert_eq!(formatter.format(&value), "-42");
+    }
+}
@@ -0,0 +1,13 @@
+//! `icu_decimal` renders numbers into locale-appropriate strings.
+//!
+//! [`FixedDecimal`] is an arbitrary-magnitude decimal value with explicit
+//! visible digit positions (so e.g. `1.50` keeps its trailing zero).
+//! [`FixedDecimalFormat`] renders one using a locale's
+//! `icu_data_provider::decimal::SymbolsV1` — the digit set and separators
+//! CLDR defines for that locale.
+
+mod fixed_decimal;
+mod format;
+
+pub use fixed_decimal::{FixedDecimal, FixedDecimalError};
+pub use format::{FixedDecimalFormat, GroupingStrategy};
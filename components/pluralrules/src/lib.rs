@@ -0,0 +1,147 @@
+//! `icu_pluralrules` implements the [Unicode CLDR plural rules](https://unicode.org/reports/tr35/tr35-numbers.html#Language_Plural_Rules),
+//! which state, given a number, which plural form (`PluralCategory`) a
+//! language uses for it, e.g. `1 day` vs. `2 days` in English.
+//!
+//! The crate is split into two layers:
+//!
+//! * [`operands`] turns a number into the CLDR plural operands (`n`, `i`,
+//!   `v`, ...) that rule conditions are evaluated against.
+//! * [`rules`] parses and evaluates the rule conditions themselves,
+//!   independent of any particular locale.
+//!
+//! [`PluralRules`] ties the two together: given a locale's set of per-category
+//! conditions, it picks the [`PluralCategory`] a number falls into.
+
+pub mod operands;
+pub mod ranges;
+pub mod rules;
+
+pub use operands::PluralOperands;
+pub use ranges::PluralRanges;
+use rules::{test_condition, Condition};
+
+/// The category produced by evaluating a number against a [`PluralRules`]
+/// instance. Matches the CLDR plural category names; `Other` is the
+/// catch-all every language must support.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PluralCategory {
+    Zero,
+    One,
+    Two,
+    Few,
+    Many,
+    Other,
+}
+
+/// CLDR ships two independent sets of rules per locale: cardinals (`1 file`,
+/// `2 files`) and ordinals (`1st`, `2nd`, `3rd`). `PluralRuleType` selects
+/// which set a [`PluralRules`] instance was built from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PluralRuleType {
+    Cardinal,
+    Ordinal,
+}
+
+/// The parsed conditions for each non-`Other` plural category, in the order
+/// CLDR defines them. Every field is optional because most locales only
+/// define rules for a subset of the categories (e.g. English cardinals only
+/// define `one`; everything else falls back to `Other`).
+#[derive(Debug, Clone, Default)]
+pub struct PluralRuleList {
+    pub zero: Option<Condition>,
+    pub one: Option<Condition>,
+    pub two: Option<Condition>,
+    pub few: Option<Condition>,
+    pub many: Option<Condition>,
+}
+
+/// Selects the [`PluralCategory`] a number belongs to, for a given locale's
+/// cardinal or ordinal rules.
+///
+/// `PluralRules` itself is locale-agnostic: it's built directly from a
+/// [`PluralRuleList`] of already-parsed conditions (see
+/// [`rules::parse_condition`]), leaving locale data loading to callers such
+/// as a `DataProvider`.
+#[derive(Debug, Clone)]
+pub struct PluralRules {
+    rule_type: PluralRuleType,
+    rules: PluralRuleList,
+}
+
+impl PluralRules {
+    /// Creates a `PluralRules` for the given rule type from its parsed
+    /// per-category conditions.
+    pub fn new(rule_type: PluralRuleType, rules: PluralRuleList) -> Self {
+        Self { rule_type, rules }
+    }
+
+    /// The rule set (cardinal or ordinal) this instance was built from.
+    pub fn rule_type(&self) -> PluralRuleType {
+        self.rule_type
+    }
+
+    /// Selects the plural category for `operands`, evaluating the
+    /// conditions in CLDR order (`zero`, `one`, `two`, `few`, `many`) and
+    /// returning the first that matches, or `Other` if none do.
+    pub fn select(&self, operands: PluralOperands) -> PluralCategory {
+        let categories: [(&Option<Condition>, PluralCategory); 5] = [
+            (&self.rules.zero, PluralCategory::Zero),
+            (&self.rules.one, PluralCategory::One),
+            (&self.rules.two, PluralCategory::Two),
+            (&self.rules.few, PluralCategory::Few),
+            (&self.rules.many, PluralCategory::Many),
+        ];
+
+        for (condition, category) in categories {
+            if let Some(condition) = condition {
+                if test_condition(condition, &operands) {
+                    return category;
+                }
+            }
+        }
+        PluralCategory::Other
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rules::parse_condition;
+
+    fn english_cardinals() -> PluralRules {
+        PluralRules::new(
+            PluralRuleType::Cardinal,
+            PluralRuleList {
+                one: Some(parse_condition(b"i = 1 and v = 0").unwrap()),
+                ..Default::default()
+            },
+        )
+    }
+
+    #[test]
+    fn test_selects_matching_category() {
+        let rules = english_cardinals();
+        assert_eq!(rules.select("1".parse().unwrap()), PluralCategory::One);
+    }
+
+    #[test]
+    fn test_falls_back_to_other() {
+        let rules = english_cardinals();
+        assert_eq!(rules.select("2".parse().unwrap()), PluralCategory::Other);
+        assert_eq!(rules.select("1.5".parse().unwrap()), PluralCategory::Other);
+    }
+
+    #[test]
+    fn test_evaluates_categories_in_cldr_order() {
+        // If both `one` and `few` could match, `one` (evaluated first) wins.
+        let rules = PluralRules::new(
+            PluralRuleType::Cardinal,
+            PluralRuleList {
+                one: Some(parse_condition(b"i = 1").unwrap()),
+                few: Some(parse_condition(b"i = 1").unwrap()),
+                ..Default::default()
+            },
+        );
+        assert_eq!(rules.select("1".parse().unwrap()), PluralCategory::One);
+    }
+}
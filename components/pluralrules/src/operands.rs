@@ -0,0 +1,207 @@
+// Plural operands, as defined by
+// https://unicode.org/reports/tr35/tr35-numbers.html#Language_Plural_Rules
+
+use std::str::FromStr;
+
+/// A full plural operand representation of a number. See [CLDR Plural
+/// Rules](https://unicode.org/reports/tr35/tr35-numbers.html#Language_Plural_Rules)
+/// for more information.
+///
+/// Every field in `PluralOperands` is a number as defined in the above spec.
+/// The operands are computed from a number's decimal representation and are
+/// what `test_condition` matches a parsed plural rule condition against.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct PluralOperands {
+    /// Absolute value of input
+    pub n: f64,
+    /// Integer digits of input
+    pub i: u64,
+    /// Number of visible fraction digits with trailing zeros
+    pub v: usize,
+    /// Number of visible fraction digits without trailing zeros
+    pub w: usize,
+    /// Visible fraction digits with trailing zeros
+    pub f: u64,
+    /// Visible fraction digits without trailing zeros
+    pub t: u64,
+    /// Compact decimal exponent (CLDR `c`, synonym `e`); e.g. `6` for the
+    /// compact form of `1.2M` shown as `1.2c6`.
+    pub c: u64,
+}
+
+/// An error originating from parsing a number into `PluralOperands`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum OperandsError {
+    /// The input string could not be parsed as a decimal number.
+    Invalid,
+}
+
+impl std::fmt::Display for OperandsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Invalid => write!(f, "invalid decimal number"),
+        }
+    }
+}
+
+impl std::error::Error for OperandsError {}
+
+/// Shifts the decimal point of `integer_part.fraction_part` right by
+/// `shift` digits, as happens when a compact exponent `c` scales a mantissa
+/// by `10^c`. Returns the new `(integer_part, fraction_part)` digit strings.
+fn shift_decimal_point(integer_part: &str, fraction_part: &str, shift: u64) -> (String, String) {
+    if shift == 0 {
+        return (integer_part.to_string(), fraction_part.to_string());
+    }
+
+    let mut digits = String::with_capacity(integer_part.len() + fraction_part.len());
+    digits.push_str(integer_part);
+    digits.push_str(fraction_part);
+
+    let point = integer_part.len() as u64 + shift;
+    if point as usize >= digits.len() {
+        digits.push_str(&"0".repeat(point as usize - digits.len()));
+        (digits, String::new())
+    } else {
+        let (int_part, frac_part) = digits.split_at(point as usize);
+        (int_part.to_string(), frac_part.to_string())
+    }
+}
+
+impl FromStr for PluralOperands {
+    type Err = OperandsError;
+
+    /// Parses the plural operands from the string representation of a
+    /// decimal number, e.g. `"1.200"` produces `i = 1, v = 3, w = 1, f = 200,
+    /// t = 2`. An optional trailing `c`/`e` exponent selects the compact
+    /// decimal operand, e.g. `"1.2c6"` is the compact form of `1,200,000`:
+    /// `n`, `i`, `v`, `w`, `f`, and `t` are derived from the value scaled by
+    /// `10^c`, while `c` itself is kept unscaled.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let (mantissa, exponent) = match input.find(['c', 'e']) {
+            Some(idx) => (&input[..idx], &input[idx + 1..]),
+            None => (input, ""),
+        };
+        let c: u64 = if exponent.is_empty() {
+            0
+        } else {
+            exponent.parse().map_err(|_| OperandsError::Invalid)?
+        };
+
+        let (sign, absolute) = match mantissa.strip_prefix('-') {
+            Some(rest) => (-1f64, rest),
+            None => (1f64, mantissa),
+        };
+
+        let mut parts = absolute.splitn(2, '.');
+        let integer_part = parts.next().ok_or(OperandsError::Invalid)?;
+        let fraction_part = parts.next().unwrap_or("");
+
+        if integer_part.is_empty() || !integer_part.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(OperandsError::Invalid);
+        }
+        if !fraction_part.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(OperandsError::Invalid);
+        }
+
+        let mantissa_value: f64 = absolute.parse().map_err(|_| OperandsError::Invalid)?;
+        let n = sign * mantissa_value * 10f64.powi(c as i32);
+
+        let (scaled_integer, scaled_fraction) = shift_decimal_point(integer_part, fraction_part, c);
+
+        let i: u64 = scaled_integer.parse().map_err(|_| OperandsError::Invalid)?;
+        let v = scaled_fraction.len();
+        let w = scaled_fraction.trim_end_matches('0').len();
+        let f: u64 = if scaled_fraction.is_empty() {
+            0
+        } else {
+            scaled_fraction.parse().map_err(|_| OperandsError::Invalid)?
+        };
+        let t: u64 = if w == 0 {
+            0
+        } else {
+            scaled_fraction[..w].parse().map_err(|_| OperandsError::Invalid)?
+        };
+
+        Ok(Self { n: n.abs(), i, v, w, f, t, c })
+    }
+}
+
+macro_rules! impl_integer_type {
+    ($ty:ty) => {
+        impl From<$ty> for PluralOperands {
+            fn from(input: $ty) -> Self {
+                Self {
+                    n: (input as f64).abs(),
+                    i: (input as i128).abs() as u64,
+                    v: 0,
+                    w: 0,
+                    f: 0,
+                    t: 0,
+                    c: 0,
+                }
+            }
+        }
+    };
+}
+
+impl_integer_type!(u8);
+impl_integer_type!(u16);
+impl_integer_type!(u32);
+impl_integer_type!(u64);
+impl_integer_type!(usize);
+impl_integer_type!(i8);
+impl_integer_type!(i16);
+impl_integer_type!(i32);
+impl_integer_type!(i64);
+impl_integer_type!(isize);
+
+impl From<&str> for PluralOperands {
+    fn from(input: &str) -> Self {
+        input.parse().unwrap_or_default()
+    }
+}
+
+impl From<String> for PluralOperands {
+    fn from(input: String) -> Self {
+        input.as_str().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parses_integer() {
+        let operands: PluralOperands = "1".parse().unwrap();
+        assert_eq!(operands, PluralOperands { n: 1.0, i: 1, v: 0, w: 0, f: 0, t: 0, c: 0 });
+    }
+
+    #[test]
+    fn test_parses_fraction_with_trailing_zeros() {
+        let operands: PluralOperands = "1.200".parse().unwrap();
+        assert_eq!(operands, PluralOperands { n: 1.2, i: 1, v: 3, w: 1, f: 200, t: 2, c: 0 });
+    }
+
+    #[test]
+    fn test_rejects_invalid_input() {
+        assert_eq!("1.2.3".parse::<PluralOperands>(), Err(OperandsError::Invalid));
+    }
+
+    #[test]
+    fn test_parses_compact_exponent() {
+        // "1.2c6" is the compact form of 1,200,000.
+        let operands: PluralOperands = "1.2c6".parse().unwrap();
+        assert_eq!(
+            operands,
+            PluralOperands { n: 1_200_000.0, i: 1_200_000, v: 0, w: 0, f: 0, t: 0, c: 6 }
+        );
+    }
+
+    #[test]
+    fn test_compact_exponent_zero_matches_plain_value() {
+        let operands: PluralOperands = "1.2c0".parse().unwrap();
+        assert_eq!(operands, PluralOperands { n: 1.2, i: 1, v: 1, w: 1, f: 2, t: 2, c: 0 });
+    }
+}
@@ -0,0 +1,58 @@
+//! Plural category selection for ranges, e.g. picking between "1–2 items"
+//! and "1–2 item" depending on how a locale pluralizes the range as a
+//! whole, which need not match either endpoint's own category.
+//!
+//! See [CLDR Plural Ranges](https://unicode.org/reports/tr35/tr35-numbers.html#Plural_Ranges).
+
+use std::collections::HashMap;
+
+use crate::PluralCategory;
+
+/// Given the plural categories of a range's start and end values, selects
+/// the plural category to use for the range as a whole.
+///
+/// CLDR defines this as a per-locale lookup table keyed by
+/// `(start_category, end_category)`; pairs the table doesn't list fall back
+/// to the end value's own category, which is the CLDR-documented default
+/// for any combination a locale doesn't explicitly override.
+#[derive(Debug, Clone, Default)]
+pub struct PluralRanges {
+    data: HashMap<(PluralCategory, PluralCategory), PluralCategory>,
+}
+
+impl PluralRanges {
+    /// Creates a `PluralRanges` from a locale's `(start, end) -> range`
+    /// lookup table.
+    pub fn new(data: HashMap<(PluralCategory, PluralCategory), PluralCategory>) -> Self {
+        Self { data }
+    }
+
+    /// Selects the plural category for a range from `start` to `end`.
+    ///
+    /// Falls back to `end` when the locale's table has no entry for the
+    /// `(start, end)` pair.
+    pub fn select(&self, start: PluralCategory, end: PluralCategory) -> PluralCategory {
+        self.data.get(&(start, end)).copied().unwrap_or(end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PluralCategory::*;
+
+    #[test]
+    fn test_falls_back_to_end_category() {
+        let ranges = PluralRanges::new(HashMap::new());
+        assert_eq!(ranges.select(One, Other), Other);
+    }
+
+    #[test]
+    fn test_uses_explicit_pair() {
+        let mut data = HashMap::new();
+        data.insert((One, One), Few);
+        let ranges = PluralRanges::new(data);
+        assert_eq!(ranges.select(One, One), Few);
+        assert_eq!(ranges.select(One, Two), Two);
+    }
+}
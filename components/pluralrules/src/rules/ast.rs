@@ -0,0 +1,76 @@
+// The Abstract Syntax Tree produced by `parse`/`parse_condition` for a
+// single CLDR plural rule `condition`, e.g. `i = 1 and v = 0`.
+//
+// Grammar (simplified from https://unicode.org/reports/tr35/tr35-numbers.html#Rules):
+//
+//   condition := and_condition ('or' and_condition)*
+//   and_condition := relation ('and' relation)*
+//   relation := expr ('=' | '!=') range_list
+//   expr := operand ('mod' value)?
+//   range_list := (value | range) (',' (value | range))*
+//   range := value '..' value
+
+/// The plural operand a `Relation` is evaluated against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operand {
+    /// Absolute value of input.
+    N,
+    /// Integer digits of input.
+    I,
+    /// Number of visible fraction digits with trailing zeros.
+    V,
+    /// Number of visible fraction digits without trailing zeros.
+    W,
+    /// Visible fraction digits with trailing zeros.
+    F,
+    /// Visible fraction digits without trailing zeros.
+    T,
+    /// Compact decimal exponent (`c`, synonym `e`).
+    C,
+}
+
+/// An operand, optionally reduced modulo a constant (`i mod 5`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Expression {
+    pub operand: Operand,
+    pub modulus: Option<u64>,
+}
+
+/// A single item of a `range_list`, either a bare value or an inclusive range.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RangeListItem {
+    Value(u64),
+    Range(u64, u64),
+}
+
+/// How a `Relation` compares its expression against its `range_list`.
+///
+/// `Eq`/`NotEq` cover `=`/`!=`/`is`/`is not`/`in`/`not in`: these require the
+/// expression to land on an exact integer, so a fractional `n` never matches
+/// (CLDR: `n = 1` is `false` for `1.5`). `Within`/`NotWithin` cover `within`/
+/// `not within`, which CLDR defines as continuous range containment — the
+/// one relation a fractional `n` can satisfy, e.g. `n within 1..3` is `true`
+/// for `n = 2.5`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operator {
+    Eq,
+    NotEq,
+    Within,
+    NotWithin,
+}
+
+/// `expr ('=' | '!=') range_list`, e.g. `i = 1` or `n != 0,2..4`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Relation {
+    pub expression: Expression,
+    pub operator: Operator,
+    pub range_list: Vec<RangeListItem>,
+}
+
+/// `relation ('and' relation)*`, all of which must hold.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AndCondition(pub Vec<Relation>);
+
+/// `and_condition ('or' and_condition)*`, any of which may hold.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Condition(pub Vec<AndCondition>);
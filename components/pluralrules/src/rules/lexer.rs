@@ -0,0 +1,192 @@
+// Tokenizer for the CLDR plural rule condition grammar.
+
+use super::ast::Operand;
+
+/// A single lexical token of a plural rule condition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Token {
+    Operand(Operand),
+    And,
+    Or,
+    Mod,
+    Is,
+    Not,
+    In,
+    Within,
+    Equal,
+    NotEqual,
+    Ellipsis,
+    Comma,
+    Value(u64),
+}
+
+/// An error produced while tokenizing a condition string.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum LexerError {
+    /// An unexpected byte was encountered at the given offset.
+    UnexpectedCharacter(u8, usize),
+}
+
+/// A streaming tokenizer over the bytes of a plural rule condition.
+///
+/// `Lexer` is an `Iterator` of `Result<Token, LexerError>`; it consumes
+/// whitespace between tokens automatically.
+pub struct Lexer<'a> {
+    input: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Lexer<'a> {
+    pub fn new(input: &'a [u8]) -> Self {
+        Self { input, pos: 0 }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(b' ') | Some(b'\t') = self.input.get(self.pos) {
+            self.pos += 1;
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.input.get(self.pos).copied()
+    }
+
+    fn read_word(&mut self) -> &'a [u8] {
+        let start = self.pos;
+        while let Some(b) = self.peek() {
+            if b.is_ascii_alphabetic() {
+                self.pos += 1;
+            } else {
+                break;
+            }
+        }
+        &self.input[start..self.pos]
+    }
+
+    fn read_number(&mut self) -> u64 {
+        let start = self.pos;
+        while let Some(b) = self.peek() {
+            if b.is_ascii_digit() {
+                self.pos += 1;
+            } else {
+                break;
+            }
+        }
+        // Safe: only ASCII digits were consumed.
+        std::str::from_utf8(&self.input[start..self.pos])
+            .unwrap()
+            .parse()
+            .unwrap_or(0)
+    }
+}
+
+impl<'a> Iterator for Lexer<'a> {
+    type Item = Result<Token, LexerError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.skip_whitespace();
+        let b = self.peek()?;
+
+        let token = match b {
+            b'0'..=b'9' => Ok(Token::Value(self.read_number())),
+            b',' => {
+                self.pos += 1;
+                Ok(Token::Comma)
+            }
+            b'.' => {
+                self.pos += 1;
+                if self.peek() == Some(b'.') {
+                    self.pos += 1;
+                    Ok(Token::Ellipsis)
+                } else {
+                    Err(LexerError::UnexpectedCharacter(b'.', self.pos - 1))
+                }
+            }
+            b'=' => {
+                self.pos += 1;
+                Ok(Token::Equal)
+            }
+            b'!' => {
+                self.pos += 1;
+                if self.peek() == Some(b'=') {
+                    self.pos += 1;
+                    Ok(Token::NotEqual)
+                } else {
+                    Err(LexerError::UnexpectedCharacter(b'!', self.pos - 1))
+                }
+            }
+            b'a'..=b'z' | b'A'..=b'Z' => {
+                let start = self.pos;
+                let word = self.read_word();
+                match word {
+                    b"n" => Ok(Token::Operand(Operand::N)),
+                    b"i" => Ok(Token::Operand(Operand::I)),
+                    b"v" => Ok(Token::Operand(Operand::V)),
+                    b"w" => Ok(Token::Operand(Operand::W)),
+                    b"f" => Ok(Token::Operand(Operand::F)),
+                    b"t" => Ok(Token::Operand(Operand::T)),
+                    b"c" | b"e" => Ok(Token::Operand(Operand::C)),
+                    b"and" => Ok(Token::And),
+                    b"or" => Ok(Token::Or),
+                    b"mod" => Ok(Token::Mod),
+                    b"is" => Ok(Token::Is),
+                    b"not" => Ok(Token::Not),
+                    b"in" => Ok(Token::In),
+                    b"within" => Ok(Token::Within),
+                    _ => Err(LexerError::UnexpectedCharacter(
+                        *word.first().unwrap_or(&b),
+                        start,
+                    )),
+                }
+            }
+            _ => {
+                self.pos += 1;
+                Err(LexerError::UnexpectedCharacter(b, self.pos - 1))
+            }
+        };
+        Some(token)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tokens(input: &str) -> Vec<Token> {
+        Lexer::new(input.as_bytes())
+            .collect::<Result<Vec<_>, _>>()
+            .expect("Failed to tokenize")
+    }
+
+    #[test]
+    fn test_tokenizes_operands_and_keywords() {
+        assert_eq!(
+            tokens("i = 1 and v != 0..2, 4"),
+            vec![
+                Token::Operand(Operand::I),
+                Token::Equal,
+                Token::Value(1),
+                Token::And,
+                Token::Operand(Operand::V),
+                Token::NotEqual,
+                Token::Value(0),
+                Token::Ellipsis,
+                Token::Value(2),
+                Token::Comma,
+                Token::Value(4),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_c_and_e_are_the_same_operand() {
+        assert_eq!(tokens("c"), vec![Token::Operand(Operand::C)]);
+        assert_eq!(tokens("e"), vec![Token::Operand(Operand::C)]);
+    }
+
+    #[test]
+    fn test_rejects_unknown_word() {
+        let err = Lexer::new(b"xyz").next().unwrap().unwrap_err();
+        assert_eq!(err, LexerError::UnexpectedCharacter(b'x', 0));
+    }
+}
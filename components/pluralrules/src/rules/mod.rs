@@ -0,0 +1,16 @@
+//! Parsing and evaluation of CLDR plural rule `condition`s, e.g.
+//! `"i = 1 and v = 0"`.
+//!
+//! This module is deliberately low-level: it knows nothing about locales or
+//! CLDR data, only about the condition grammar itself. [`crate::PluralRules`]
+//! builds on top of it by pairing a parsed `Condition` per [`PluralCategory`].
+
+pub mod ast;
+pub mod lexer;
+pub mod parser;
+pub mod test_condition;
+
+pub use ast::Condition;
+pub use lexer::{Lexer, LexerError, Token};
+pub use parser::{parse, parse_condition, ParserError};
+pub use test_condition::test_condition;
@@ -0,0 +1,209 @@
+// Recursive-descent parser building a `Condition` AST out of the token
+// stream produced by `Lexer`.
+
+use super::ast::{AndCondition, Condition, Expression, Operator, RangeListItem, Relation};
+use super::lexer::{Lexer, LexerError, Token};
+
+/// An error produced while parsing a plural rule or a bare condition.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ParserError {
+    Lexer(LexerError),
+    UnexpectedEof,
+    UnexpectedToken(Token),
+}
+
+impl From<LexerError> for ParserError {
+    fn from(err: LexerError) -> Self {
+        Self::Lexer(err)
+    }
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn new(input: &[u8]) -> Result<Self, ParserError> {
+        let tokens = Lexer::new(input)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self { tokens, pos: 0 })
+    }
+
+    fn peek(&self) -> Option<Token> {
+        self.tokens.get(self.pos).copied()
+    }
+
+    fn bump(&mut self) -> Option<Token> {
+        let token = self.peek();
+        if token.is_some() {
+            self.pos += 1;
+        }
+        token
+    }
+
+    fn expect_value(&mut self) -> Result<u64, ParserError> {
+        match self.bump() {
+            Some(Token::Value(v)) => Ok(v),
+            Some(token) => Err(ParserError::UnexpectedToken(token)),
+            None => Err(ParserError::UnexpectedEof),
+        }
+    }
+
+    fn parse_condition(&mut self) -> Result<Condition, ParserError> {
+        let mut and_conditions = vec![self.parse_and_condition()?];
+        while self.peek() == Some(Token::Or) {
+            self.bump();
+            and_conditions.push(self.parse_and_condition()?);
+        }
+        Ok(Condition(and_conditions))
+    }
+
+    fn parse_and_condition(&mut self) -> Result<AndCondition, ParserError> {
+        let mut relations = vec![self.parse_relation()?];
+        while self.peek() == Some(Token::And) {
+            self.bump();
+            relations.push(self.parse_relation()?);
+        }
+        Ok(AndCondition(relations))
+    }
+
+    fn parse_relation(&mut self) -> Result<Relation, ParserError> {
+        let expression = self.parse_expression()?;
+        let operator = match self.bump() {
+            Some(Token::Is) => {
+                // `i is 1` / `i is not 1`
+                if self.peek() == Some(Token::Not) {
+                    self.bump();
+                    Operator::NotEq
+                } else {
+                    Operator::Eq
+                }
+            }
+            Some(Token::Equal) | Some(Token::In) => Operator::Eq,
+            Some(Token::NotEqual) => Operator::NotEq,
+            Some(Token::Within) => Operator::Within,
+            Some(Token::Not) => {
+                // `n not in 2..4` / `n not within 2..4`
+                match self.bump() {
+                    Some(Token::Is) | Some(Token::In) => Operator::NotEq,
+                    Some(Token::Within) => Operator::NotWithin,
+                    Some(token) => return Err(ParserError::UnexpectedToken(token)),
+                    None => return Err(ParserError::UnexpectedEof),
+                }
+            }
+            Some(token) => return Err(ParserError::UnexpectedToken(token)),
+            None => return Err(ParserError::UnexpectedEof),
+        };
+        let range_list = self.parse_range_list()?;
+        Ok(Relation { expression, operator, range_list })
+    }
+
+    fn parse_expression(&mut self) -> Result<Expression, ParserError> {
+        let operand = match self.bump() {
+            Some(Token::Operand(operand)) => operand,
+            Some(token) => return Err(ParserError::UnexpectedToken(token)),
+            None => return Err(ParserError::UnexpectedEof),
+        };
+        let modulus = if self.peek() == Some(Token::Mod) {
+            self.bump();
+            Some(self.expect_value()?)
+        } else {
+            None
+        };
+        Ok(Expression { operand, modulus })
+    }
+
+    fn parse_range_list(&mut self) -> Result<Vec<RangeListItem>, ParserError> {
+        let mut items = vec![self.parse_range_list_item()?];
+        while self.peek() == Some(Token::Comma) {
+            self.bump();
+            items.push(self.parse_range_list_item()?);
+        }
+        Ok(items)
+    }
+
+    fn parse_range_list_item(&mut self) -> Result<RangeListItem, ParserError> {
+        let start = self.expect_value()?;
+        if self.peek() == Some(Token::Ellipsis) {
+            self.bump();
+            let end = self.expect_value()?;
+            Ok(RangeListItem::Range(start, end))
+        } else {
+            Ok(RangeListItem::Value(start))
+        }
+    }
+}
+
+/// Parses a plural rule `condition`, rejecting any tokens left over after
+/// it. CLDR's optional `samples` suffix (`@integer ...`, `@decimal ...`) is
+/// not supported: input containing one fails with a lexer error.
+pub fn parse(input: &[u8]) -> Result<Condition, ParserError> {
+    let mut parser = Parser::new(input)?;
+    let condition = parser.parse_condition()?;
+    match parser.peek() {
+        None => Ok(condition),
+        Some(token) => Err(ParserError::UnexpectedToken(token)),
+    }
+}
+
+/// Parses a bare plural rule `condition`, e.g. `"i = 1 and v = 0"`.
+pub fn parse_condition(input: &[u8]) -> Result<Condition, ParserError> {
+    parse(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::ast::Operand;
+
+    #[test]
+    fn test_parses_simple_relation() {
+        let condition = parse_condition(b"i = 1").unwrap();
+        assert_eq!(
+            condition,
+            Condition(vec![AndCondition(vec![Relation {
+                expression: Expression { operand: Operand::I, modulus: None },
+                operator: Operator::Eq,
+                range_list: vec![RangeListItem::Value(1)],
+            }])])
+        );
+    }
+
+    #[test]
+    fn test_parses_modulus_and_range_list() {
+        let condition = parse_condition(b"i mod 10 = 2..4, 7").unwrap();
+        let relation = &condition.0[0].0[0];
+        assert_eq!(relation.expression.modulus, Some(10));
+        assert_eq!(
+            relation.range_list,
+            vec![RangeListItem::Range(2, 4), RangeListItem::Value(7)]
+        );
+    }
+
+    #[test]
+    fn test_parses_and_or_precedence() {
+        let condition = parse_condition(b"i = 1 and v = 0 or i = 2").unwrap();
+        assert_eq!(condition.0.len(), 2);
+        assert_eq!(condition.0[0].0.len(), 2);
+        assert_eq!(condition.0[1].0.len(), 1);
+    }
+
+    #[test]
+    fn test_rejects_incomplete_condition() {
+        let err = parse(b"i = 1 and").unwrap_err();
+        assert_eq!(err, ParserError::UnexpectedEof);
+    }
+
+    #[test]
+    fn test_within_is_distinct_from_in() {
+        let condition = parse_condition(b"n within 1..3").unwrap();
+        assert_eq!(condition.0[0].0[0].operator, Operator::Within);
+
+        let condition = parse_condition(b"n not within 1..3").unwrap();
+        assert_eq!(condition.0[0].0[0].operator, Operator::NotWithin);
+
+        let condition = parse_condition(b"n in 1..3").unwrap();
+        assert_eq!(condition.0[0].0[0].operator, Operator::Eq);
+    }
+}
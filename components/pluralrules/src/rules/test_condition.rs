@@ -0,0 +1,148 @@
+// Evaluates a parsed `Condition` against a concrete set of `PluralOperands`.
+
+use super::ast::{Condition, Expression, Operand, Operator, RangeListItem, Relation};
+use crate::operands::PluralOperands;
+
+/// Resolves `expression` to the integer it should be compared against a
+/// `range_list` with, or `None` if it can never equal one: `n` is the one
+/// operand that keeps its fractional part (CLDR requires `n = 1` to be
+/// `false` for `1.5`), so a non-integral `n` can't match any range-list
+/// value regardless of modulus.
+fn operand_value(expression: &Expression, operands: &PluralOperands) -> Option<u64> {
+    let value = match expression.operand {
+        Operand::N => {
+            if operands.n.fract() != 0.0 {
+                return None;
+            }
+            operands.n as u64
+        }
+        Operand::I => operands.i,
+        Operand::V => operands.v as u64,
+        Operand::W => operands.w as u64,
+        Operand::F => operands.f,
+        Operand::T => operands.t,
+        Operand::C => operands.c,
+    };
+    Some(match expression.modulus {
+        Some(modulus) if modulus > 0 => value % modulus,
+        _ => value,
+    })
+}
+
+/// Resolves `expression` to the `f64` it should be compared against a
+/// `range_list` under `within`/`not within`, which — unlike `=`/`in` —
+/// compares continuously rather than requiring an exact integer, so a
+/// fractional `n` can fall inside a range (CLDR: `n within 1..3` is `true`
+/// for `n = 2.5`).
+fn float_operand_value(expression: &Expression, operands: &PluralOperands) -> f64 {
+    let value = match expression.operand {
+        Operand::N => operands.n,
+        Operand::I => operands.i as f64,
+        Operand::V => operands.v as f64,
+        Operand::W => operands.w as f64,
+        Operand::F => operands.f as f64,
+        Operand::T => operands.t as f64,
+        Operand::C => operands.c as f64,
+    };
+    match expression.modulus {
+        Some(modulus) if modulus > 0 => value % modulus as f64,
+        _ => value,
+    }
+}
+
+fn in_range_list_continuous(range_list: &[RangeListItem], value: f64) -> bool {
+    range_list.iter().any(|item| match item {
+        RangeListItem::Value(v) => value == *v as f64,
+        RangeListItem::Range(start, end) => (*start as f64..=*end as f64).contains(&value),
+    })
+}
+
+fn relation_matches(relation: &Relation, operands: &PluralOperands) -> bool {
+    match relation.operator {
+        Operator::Eq | Operator::NotEq => {
+            let in_range_list = match operand_value(&relation.expression, operands) {
+                Some(value) => relation.range_list.iter().any(|item| match item {
+                    RangeListItem::Value(v) => value == *v,
+                    RangeListItem::Range(start, end) => (*start..=*end).contains(&value),
+                }),
+                None => false,
+            };
+            match relation.operator {
+                Operator::Eq => in_range_list,
+                Operator::NotEq => !in_range_list,
+                Operator::Within | Operator::NotWithin => unreachable!(),
+            }
+        }
+        Operator::Within | Operator::NotWithin => {
+            let value = float_operand_value(&relation.expression, operands);
+            let in_range_list = in_range_list_continuous(&relation.range_list, value);
+            match relation.operator {
+                Operator::Within => in_range_list,
+                Operator::NotWithin => !in_range_list,
+                Operator::Eq | Operator::NotEq => unreachable!(),
+            }
+        }
+    }
+}
+
+/// Evaluates a parsed plural rule `condition` against a set of operands,
+/// returning `true` if the condition holds.
+pub fn test_condition(condition: &Condition, operands: &PluralOperands) -> bool {
+    condition
+        .0
+        .iter()
+        .any(|and_condition| and_condition.0.iter().all(|relation| relation_matches(relation, operands)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::parse_condition;
+
+    #[test]
+    fn test_simple_equality() {
+        let condition = parse_condition(b"i = 1").unwrap();
+        let operands: PluralOperands = "1".parse().unwrap();
+        assert!(test_condition(&condition, &operands));
+    }
+
+    #[test]
+    fn test_and_or() {
+        let condition = parse_condition(b"v = 0 and i = 1 or i = 2").unwrap();
+        assert!(test_condition(&condition, &"1".parse().unwrap()));
+        assert!(test_condition(&condition, &"2.0".parse::<PluralOperands>().unwrap()));
+        assert!(!test_condition(&condition, &"1.5".parse().unwrap()));
+    }
+
+    #[test]
+    fn test_fractional_n_never_equals_an_integer() {
+        // Welsh cardinal `one: n = 1` must not match 1.5.
+        let condition = parse_condition(b"n = 1").unwrap();
+        assert!(test_condition(&condition, &"1".parse().unwrap()));
+        assert!(!test_condition(&condition, &"1.5".parse().unwrap()));
+        // ...but `n != 1` must hold for it.
+        let not_condition = parse_condition(b"n != 1").unwrap();
+        assert!(test_condition(&not_condition, &"1.5".parse().unwrap()));
+    }
+
+    #[test]
+    fn test_within_allows_fractional_n() {
+        // Unlike `in`/`=`, `within` is continuous range containment, so a
+        // fractional `n` can fall inside it.
+        let condition = parse_condition(b"n within 1..3").unwrap();
+        assert!(test_condition(&condition, &"2.5".parse().unwrap()));
+        assert!(test_condition(&condition, &"1".parse().unwrap()));
+        assert!(!test_condition(&condition, &"3.5".parse().unwrap()));
+
+        let not_condition = parse_condition(b"n not within 1..3").unwrap();
+        assert!(!test_condition(&not_condition, &"2.5".parse().unwrap()));
+        assert!(test_condition(&not_condition, &"3.5".parse().unwrap()));
+    }
+
+    #[test]
+    fn test_modulus_and_range() {
+        let condition = parse_condition(b"i mod 10 = 2..4").unwrap();
+        assert!(test_condition(&condition, &"12".parse().unwrap()));
+        assert!(!test_condition(&condition, &"15".parse().unwrap()));
+    }
+}
@@ -0,0 +1,23 @@
+// Deserialization target for `./fixtures/rules.json`.
+
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub struct RuleTestSet(pub Vec<RuleTest>);
+
+#[derive(Deserialize)]
+pub struct RuleTest {
+    pub rule: String,
+    #[serde(default)]
+    pub input: String,
+    pub output: RuleTestOutput,
+}
+
+/// A rule either parses and evaluates to `Value(bool)`, or fails to parse
+/// with a `{:?}`-formatted `Error(String)`.
+#[derive(Deserialize)]
+#[serde(untagged)]
+pub enum RuleTestOutput {
+    Value(bool),
+    Error(String),
+}
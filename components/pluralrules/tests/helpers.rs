@@ -0,0 +1,11 @@
+// Shared helpers for loading JSON test fixtures.
+
+use std::error::Error;
+use std::fs;
+
+use serde::de::DeserializeOwned;
+
+pub fn read_fixture<T: DeserializeOwned>(path: &str) -> Result<T, Box<dyn Error>> {
+    let contents = fs::read_to_string(path)?;
+    Ok(serde_json::from_str(&contents)?)
+}
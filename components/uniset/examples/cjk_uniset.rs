@@ -7,4 +7,11 @@ fn main() -> Result<(), UnicodeSetError> {
   let cjk1 = vec![0x4E00, 0x62FF];
 
   let cjk1_set = UnicodeSet::from_inversion_list(cjk1)?;
+  println!("U+4E00 in set: {}", cjk1_set.contains(0x4E00));
+
+  let cjk2_set = UnicodeSet::from_pattern("[\\u3400-\\u4DBF]")?;
+  let cjk_set = cjk1_set.union(&cjk2_set);
+  println!("U+3500 in set: {}", cjk_set.contains(0x3500));
+
+  Ok(())
 }
@@ -0,0 +1,38 @@
+use std::fmt;
+
+/// An error produced while constructing or parsing a [`crate::UnicodeSet`].
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum UnicodeSetError {
+    /// The given inversion list does not satisfy the invariant required of
+    /// one: strictly increasing, even length, alternating in/out.
+    InvalidInversionList(Vec<u32>),
+    /// A range's start was greater than its end.
+    InvalidRange(u32, u32),
+    /// An unexpected character was found at the given byte offset while
+    /// parsing a `[...]` pattern.
+    UnexpectedCharacter(char, usize),
+    /// A `\u`/`\U` escape was malformed or did not name a valid code point.
+    InvalidEscape(String),
+    /// The pattern ended before a `]` closed every open `[`.
+    UnexpectedEof,
+}
+
+impl fmt::Display for UnicodeSetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidInversionList(list) => {
+                write!(f, "invalid inversion list: {:?}", list)
+            }
+            Self::InvalidRange(start, end) => {
+                write!(f, "invalid range: {:#X}..{:#X}", start, end)
+            }
+            Self::UnexpectedCharacter(c, offset) => {
+                write!(f, "unexpected character {:?} at offset {}", c, offset)
+            }
+            Self::InvalidEscape(escape) => write!(f, "invalid escape sequence: {}", escape),
+            Self::UnexpectedEof => write!(f, "unexpected end of pattern"),
+        }
+    }
+}
+
+impl std::error::Error for UnicodeSetError {}
@@ -0,0 +1,219 @@
+//! `icu_unicodeset` models a set of Unicode code points as an *inversion
+//! list*: a sorted list of boundaries where membership toggles, e.g. the set
+//! `{'a'..='z'}` is stored as `[0x61, 0x7B]`. This representation is
+//! compact and makes set algebra a single linear merge over two sorted
+//! arrays (see [`UnicodeSet::union`] and friends).
+
+mod error;
+mod pattern;
+
+pub use error::UnicodeSetError;
+
+/// The exclusive upper bound of all Unicode code points.
+const CODE_POINT_LIMIT: u32 = 0x110000;
+
+/// A set of Unicode code points, represented as a sorted inversion list.
+///
+/// An inversion list is a `Vec<u32>` of strictly increasing boundaries,
+/// always of even length, where `list[0]..list[1]` is "in" the set,
+/// `list[1]..list[2]` is "out", `list[2]..list[3]` is "in" again, and so on.
+/// An empty list represents the empty set.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct UnicodeSet {
+    inv_list: Vec<u32>,
+}
+
+fn validate_inversion_list(list: &[u32]) -> Result<(), UnicodeSetError> {
+    if list.len() % 2 != 0 {
+        return Err(UnicodeSetError::InvalidInversionList(list.to_vec()));
+    }
+    if !list.windows(2).all(|pair| pair[0] < pair[1]) {
+        return Err(UnicodeSetError::InvalidInversionList(list.to_vec()));
+    }
+    Ok(())
+}
+
+/// Merges two sorted inversion lists into the one for which
+/// `predicate(in_a, in_b)` holds, toggling each operand's membership at its
+/// own boundaries and emitting a boundary into the result whenever the
+/// predicate's truth value changes.
+fn merge(a: &[u32], b: &[u32], predicate: impl Fn(bool, bool) -> bool) -> Vec<u32> {
+    let mut result = Vec::new();
+    let (mut ai, mut bi) = (0usize, 0usize);
+    let (mut a_in, mut b_in) = (false, false);
+    let mut current = predicate(false, false);
+
+    while ai < a.len() || bi < b.len() {
+        let next_a = a.get(ai);
+        let next_b = b.get(bi);
+        let boundary = match (next_a, next_b) {
+            (Some(&x), Some(&y)) => x.min(y),
+            (Some(&x), None) => x,
+            (None, Some(&y)) => y,
+            (None, None) => unreachable!(),
+        };
+        if next_a == Some(&boundary) {
+            a_in = !a_in;
+            ai += 1;
+        }
+        if next_b == Some(&boundary) {
+            b_in = !b_in;
+            bi += 1;
+        }
+        let new_state = predicate(a_in, b_in);
+        if new_state != current {
+            result.push(boundary);
+            current = new_state;
+        }
+    }
+    result
+}
+
+impl UnicodeSet {
+    /// Constructs a `UnicodeSet` from an inversion list, validating that it
+    /// is strictly increasing and of even length.
+    pub fn from_inversion_list(inv_list: Vec<u32>) -> Result<Self, UnicodeSetError> {
+        validate_inversion_list(&inv_list)?;
+        Ok(Self { inv_list })
+    }
+
+    /// Parses a `UnicodeSet` from an ICU-style set pattern, e.g.
+    /// `"[a-zÀ-ÿ]"`, including ranges, `\u`/`\U` escapes, negation (`[^...]`)
+    /// and nested set operations (`&` intersection, `-` difference).
+    pub fn from_pattern(pattern: &str) -> Result<Self, UnicodeSetError> {
+        pattern::parse(pattern)
+    }
+
+    /// The underlying inversion list.
+    pub fn as_inversion_list(&self) -> &[u32] {
+        &self.inv_list
+    }
+
+    /// Returns `true` if every code point in `start..=end` is in the set.
+    pub fn contains_range(&self, start: u32, end: u32) -> bool {
+        if start > end {
+            return false;
+        }
+        let idx = self.inv_list.partition_point(|&b| b <= start);
+        // Even-indexed runs (0, 2, 4, ...) are "in"; `idx` counts boundaries
+        // at or before `start`, so an odd `idx` means `start` fell inside
+        // an "in" run that started at `inv_list[idx - 1]`.
+        if idx % 2 == 0 {
+            return false;
+        }
+        match self.inv_list.get(idx) {
+            Some(&upper_bound_exclusive) => upper_bound_exclusive > end,
+            None => false,
+        }
+    }
+
+    /// Returns `true` if `c` is in the set.
+    pub fn contains(&self, c: u32) -> bool {
+        self.contains_range(c, c)
+    }
+
+    /// The set of all code points in either `self` or `other`.
+    pub fn union(&self, other: &Self) -> Self {
+        Self {
+            inv_list: merge(&self.inv_list, &other.inv_list, |a, b| a || b),
+        }
+    }
+
+    /// The set of all code points in both `self` and `other`.
+    pub fn intersection(&self, other: &Self) -> Self {
+        Self {
+            inv_list: merge(&self.inv_list, &other.inv_list, |a, b| a && b),
+        }
+    }
+
+    /// The set of all code points in `self` but not in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        Self {
+            inv_list: merge(&self.inv_list, &other.inv_list, |a, b| a && !b),
+        }
+    }
+
+    /// The set of all code points in `[0, 0x110000)` that are not in `self`.
+    pub fn complement(&self) -> Self {
+        let mut result = Vec::with_capacity(self.inv_list.len() + 2);
+        if self.inv_list.first() == Some(&0) {
+            result.extend_from_slice(&self.inv_list[1..]);
+        } else {
+            result.push(0);
+            result.extend_from_slice(&self.inv_list);
+        }
+        if result.last() == Some(&CODE_POINT_LIMIT) {
+            result.pop();
+        } else {
+            result.push(CODE_POINT_LIMIT);
+        }
+        Self { inv_list: result }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(ranges: &[(u32, u32)]) -> UnicodeSet {
+        let mut inv_list = Vec::new();
+        for (start, end) in ranges {
+            inv_list.push(*start);
+            inv_list.push(*end + 1);
+        }
+        UnicodeSet::from_inversion_list(inv_list).unwrap()
+    }
+
+    #[test]
+    fn test_rejects_invalid_inversion_list() {
+        assert!(UnicodeSet::from_inversion_list(vec![5, 3]).is_err());
+        assert!(UnicodeSet::from_inversion_list(vec![1, 2, 3]).is_err());
+    }
+
+    #[test]
+    fn test_contains_range() {
+        let s = set(&[(0x61, 0x7A)]);
+        assert!(s.contains_range(0x61, 0x7A));
+        assert!(s.contains('m' as u32));
+        assert!(!s.contains_range(0x60, 0x7A));
+        assert!(!s.contains('A' as u32));
+    }
+
+    #[test]
+    fn test_union() {
+        let a = set(&[(0x61, 0x6A)]); // a..j
+        let b = set(&[(0x70, 0x7A)]); // p..z
+        let u = a.union(&b);
+        assert!(u.contains('a' as u32));
+        assert!(u.contains('z' as u32));
+        assert!(!u.contains('n' as u32));
+    }
+
+    #[test]
+    fn test_intersection() {
+        let a = set(&[(0x61, 0x7A)]); // a..z
+        let b = set(&[(0x6D, 0x90)]); // m..
+        let i = a.intersection(&b);
+        assert!(i.contains('m' as u32));
+        assert!(i.contains('z' as u32));
+        assert!(!i.contains('a' as u32));
+    }
+
+    #[test]
+    fn test_difference() {
+        let a = set(&[(0x61, 0x7A)]); // a..z
+        let b = set(&[(0x61, 0x65)]); // a..e
+        let d = a.difference(&b);
+        assert!(!d.contains('a' as u32));
+        assert!(d.contains('f' as u32));
+    }
+
+    #[test]
+    fn test_complement() {
+        let a = set(&[(0x61, 0x7A)]);
+        let c = a.complement();
+        assert!(!c.contains('a' as u32));
+        assert!(c.contains('A' as u32));
+        assert!(c.contains(0x10FFFF));
+    }
+}
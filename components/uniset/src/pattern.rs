@@ -0,0 +1,256 @@
+//! Parses the subset of ICU's `UnicodeSet` pattern syntax needed to build a
+//! [`crate::UnicodeSet`] from a string, e.g. `"[a-zÀ-ÿ]"`.
+//!
+//! Supported grammar, informally:
+//!
+//! ```text
+//! set        := '[' '^'? clause (operator? clause)* ']'
+//! operator   := '&' | '-'
+//! clause     := set | item
+//! item       := value ('-' value)?
+//! value      := '\u' hex{4} | '\U00' hex{6} | '\' any | any
+//! ```
+//!
+//! Adjacent clauses with no operator between them are unioned; `&` is
+//! intersection and `-` is difference, both left-associative over the
+//! clauses seen so far.
+
+use std::iter::Peekable;
+use std::str::CharIndices;
+
+use crate::{UnicodeSet, UnicodeSetError};
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Operator {
+    Union,
+    Intersection,
+    Difference,
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    chars: Peekable<CharIndices<'a>>,
+}
+
+impl<'a> Parser<'a> {
+    fn new(input: &'a str) -> Self {
+        Self {
+            input,
+            chars: input.char_indices().peekable(),
+        }
+    }
+
+    fn peek_char(&mut self) -> Option<char> {
+        self.chars.peek().map(|&(_, c)| c)
+    }
+
+    fn bump(&mut self) -> Option<(usize, char)> {
+        self.chars.next()
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), UnicodeSetError> {
+        match self.bump() {
+            Some((_, c)) if c == expected => Ok(()),
+            Some((offset, c)) => Err(UnicodeSetError::UnexpectedCharacter(c, offset)),
+            None => Err(UnicodeSetError::UnexpectedEof),
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek_char(), Some(' ') | Some('\t') | Some('\n')) {
+            self.bump();
+        }
+    }
+
+    /// Reads one code point value: a `\u`/`\U` escape, an escaped literal,
+    /// or a plain character.
+    fn read_value(&mut self) -> Result<u32, UnicodeSetError> {
+        match self.bump() {
+            Some((offset, '\\')) => self.read_escape(offset),
+            Some((_, c)) => Ok(c as u32),
+            None => Err(UnicodeSetError::UnexpectedEof),
+        }
+    }
+
+    fn read_hex(&mut self, count: usize, escape_so_far: &str) -> Result<u32, UnicodeSetError> {
+        let mut digits = String::with_capacity(count);
+        for _ in 0..count {
+            match self.bump() {
+                Some((_, c)) if c.is_ascii_hexdigit() => digits.push(c),
+                _ => {
+                    return Err(UnicodeSetError::InvalidEscape(format!(
+                        "{}{}",
+                        escape_so_far, digits
+                    )))
+                }
+            }
+        }
+        u32::from_str_radix(&digits, 16)
+            .ok()
+            .filter(|&cp| cp < 0x110000)
+            .ok_or_else(|| UnicodeSetError::InvalidEscape(format!("{}{}", escape_so_far, digits)))
+    }
+
+    fn read_escape(&mut self, backslash_offset: usize) -> Result<u32, UnicodeSetError> {
+        match self.bump() {
+            Some((_, 'u')) => self.read_hex(4, "\\u"),
+            Some((_, 'U')) => {
+                self.expect('0')?;
+                self.expect('0')?;
+                self.read_hex(6, "\\U00")
+            }
+            Some((_, c)) => Ok(c as u32),
+            None => Err(UnicodeSetError::UnexpectedCharacter('\\', backslash_offset)),
+        }
+    }
+
+    /// Reads a single `item`: a value, optionally extended into a range by
+    /// a trailing `-value`. A `-` is only consumed as a range dash when it
+    /// is not immediately followed by `[` or `]`, which instead mark the
+    /// `-` as the difference operator before the next clause.
+    fn read_item(&mut self) -> Result<UnicodeSet, UnicodeSetError> {
+        let start = self.read_value()?;
+        let is_range = self.peek_char() == Some('-')
+            && !matches!(self.peek_second_char(), Some('[') | Some(']') | None);
+        let end = if is_range {
+            self.bump(); // consume '-'
+            self.read_value()?
+        } else {
+            start
+        };
+        if start > end {
+            return Err(UnicodeSetError::InvalidRange(start, end));
+        }
+        UnicodeSet::from_inversion_list(vec![start, end + 1])
+    }
+
+    /// Looks past the immediate next character to the one after it, without
+    /// consuming either. Used to disambiguate a range `-` from an operator
+    /// `-` that precedes a nested `[...]` clause.
+    fn peek_second_char(&mut self) -> Option<char> {
+        let mut iter = self.input[self.chars.peek().map(|&(i, _)| i).unwrap_or(self.input.len())..]
+            .char_indices();
+        iter.next();
+        iter.next().map(|(_, c)| c)
+    }
+
+    fn read_clause(&mut self) -> Result<UnicodeSet, UnicodeSetError> {
+        if self.peek_char() == Some('[') {
+            self.parse_set()
+        } else {
+            self.read_item()
+        }
+    }
+
+    fn parse_set(&mut self) -> Result<UnicodeSet, UnicodeSetError> {
+        self.expect('[')?;
+        let negated = if self.peek_char() == Some('^') {
+            self.bump();
+            true
+        } else {
+            false
+        };
+
+        let mut accumulator: Option<UnicodeSet> = None;
+        loop {
+            self.skip_whitespace();
+            match self.peek_char() {
+                Some(']') => {
+                    self.bump();
+                    break;
+                }
+                None => return Err(UnicodeSetError::UnexpectedEof),
+                _ => {}
+            }
+
+            let operator = match (self.peek_char(), &accumulator) {
+                (Some('&'), Some(_)) => {
+                    self.bump();
+                    Operator::Intersection
+                }
+                // A `-` immediately before `]` is a literal dash (see
+                // `read_item`'s matching `peek_second_char` check), not the
+                // difference operator, so it's left for `read_clause` to
+                // parse as an item.
+                (Some('-'), Some(_)) if self.peek_second_char() != Some(']') => {
+                    self.bump();
+                    Operator::Difference
+                }
+                _ => Operator::Union,
+            };
+            self.skip_whitespace();
+
+            let clause = self.read_clause()?;
+            accumulator = Some(match accumulator {
+                None => clause,
+                Some(acc) => match operator {
+                    Operator::Union => acc.union(&clause),
+                    Operator::Intersection => acc.intersection(&clause),
+                    Operator::Difference => acc.difference(&clause),
+                },
+            });
+        }
+
+        let set = accumulator.unwrap_or_default();
+        Ok(if negated { set.complement() } else { set })
+    }
+}
+
+pub(crate) fn parse(pattern: &str) -> Result<UnicodeSet, UnicodeSetError> {
+    let mut parser = Parser::new(pattern);
+    let set = parser.parse_set()?;
+    match parser.bump() {
+        None => Ok(set),
+        Some((offset, c)) => Err(UnicodeSetError::UnexpectedCharacter(c, offset)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parses_simple_range() {
+        let set = parse("[a-z]").unwrap();
+        assert!(set.contains('m' as u32));
+        assert!(!set.contains('A' as u32));
+    }
+
+    #[test]
+    fn test_parses_implicit_union() {
+        let set = parse("[a-z\\u00C0-\\u00FF]").unwrap();
+        assert!(set.contains('m' as u32));
+        assert!(set.contains(0xE0));
+        assert!(!set.contains('A' as u32));
+    }
+
+    #[test]
+    fn test_parses_negation() {
+        let set = parse("[^a-z]").unwrap();
+        assert!(!set.contains('m' as u32));
+        assert!(set.contains('A' as u32));
+    }
+
+    #[test]
+    fn test_parses_nested_intersection() {
+        let set = parse("[[a-z]&[m-z]]").unwrap();
+        assert!(!set.contains('a' as u32));
+        assert!(set.contains('m' as u32));
+    }
+
+    #[test]
+    fn test_parses_literal_dash_before_close_bracket() {
+        // A `-` immediately before `]` is a literal character, not the
+        // difference operator.
+        let set = parse("[a-z-]").unwrap();
+        assert!(set.contains('m' as u32));
+        assert!(set.contains('-' as u32));
+    }
+
+    #[test]
+    fn test_parses_difference() {
+        let set = parse("[a-z-[aeiou]]").unwrap();
+        assert!(set.contains('b' as u32));
+        assert!(!set.contains('a' as u32));
+    }
+}